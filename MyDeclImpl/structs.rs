@@ -15,14 +15,17 @@
 ///         foo: u8 = 233,
 ///         pub bar: &'a str = "abc",
 ///     }
+///     #[derive(Debug)]
+///     pub struct D(u8 = 233, String = "x".into());
 ///     struct B {}
 ///     struct C;
 /// );
-/// 
+///
 /// assert_eqs!(
 ///     233, A::default().foo;
 ///     "abc", A::default().bar;
 ///     "A { foo: 233, bar: \"abc\" }", format!("{:?}", A::default());
+///     "D(233, \"x\")", format!("{:?}", D::default());
 /// );
 /// ```
 #[macro_export]
@@ -49,6 +52,26 @@ macro_rules! struct_default {
         }
     };
 
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $(<$($generic:tt),*>)? (
+        $($field_vis:vis $type:ty = $val:expr),* $(,)?
+    );
+    $($tail:tt)*) => {
+        $(#[$attr])*
+        $vis struct $name $(<$($generic),*>)? (
+            $($field_vis $type),*
+        );
+        impl $(<$($generic),*>)? Default for $name $(<$($generic),*>)? {
+            fn default() -> Self {
+                $name (
+                    $($val),*
+                )
+            }
+        }
+        struct_default! {
+            $($tail)*
+        }
+    };
+
     () => {}
 }
 
@@ -108,3 +131,546 @@ macro_rules! struct_new {
 
     () => {}
 }
+
+/// `Struct::new(...)`: like [`struct_new!`], but every constructor parameter
+/// accepts `impl Into<$p_type>` instead of the exact `$p_type`.
+///
+/// # Principles
+///
+/// Text replacement, automatic function generation.
+///
+/// # Limitations
+///
+/// Don't use this on a parameter whose type is (or mentions) one of the
+/// struct's own generic type parameters, e.g. `pub struct A<T>(pub foo: T,)`.
+/// `impl Into<T>` over a bare `T` is ambiguous at the call site (`E0283: type
+/// annotations needed`), since the compiler can no longer infer `T` from the
+/// argument alone. Reach for [`struct_new!`] for those fields instead; this
+/// macro is meant for parameters with a concrete destination type (`String`,
+/// `u64`, a local newtype, ...) where the `Into` conversion is unambiguous.
+///
+/// # Examples
+///
+/// ``` rust
+/// use aoko::{struct_new_into, assert_eqs};
+///
+/// struct_new_into!(
+///     #[derive(Debug)]
+///     pub struct A(pub foo: String,) {
+///         pub bar: u64 = 0,
+///     }
+///     struct B {}
+///     struct C;
+/// );
+///
+/// let test = A::new("foo");
+///
+/// assert_eqs!(
+///     "foo", test.foo;
+///     0, test.bar;
+///     format!("{:?}", test), "A { foo: \"foo\", bar: 0 }";
+/// );
+/// ```
+#[macro_export]
+macro_rules! struct_new_into {
+    ($vis:vis struct $s_name:ident;) => {$vis struct $s_name;};
+
+    ($(#[$attr:meta])* $vis:vis struct $s_name:ident $(<$($generic:tt),*>)? $(($($p_vis:vis $p_name:ident: $p_type:ty),* $(,)?))? $(where $($id:tt: $limit:tt),*)? {
+        $($field_vis:vis $field:ident: $type:ty = $val:expr),* $(,)?
+    }
+    $($tail:tt)*) => {
+        $(#[$attr])*
+        $vis struct $s_name $(<$($generic),*>)? $(where $($id: $limit),*)? {
+            $($($p_vis $p_name: $p_type,)*)?
+            $($field_vis $field: $type),*
+        }
+        impl $(<$($generic),*>)? $s_name $(<$($generic),*>)? $(where $($id: $limit),*)? {
+            fn new($($($p_name: impl Into<$p_type>),*)?) -> Self {
+                $s_name {
+                    $($($p_name: $p_name.into(),)*)?
+                    $($field: $val),*
+                }
+            }
+        }
+        struct_new_into! {
+            $($tail)*
+        }
+    };
+
+    () => {}
+}
+
+/// `Struct::builder()...build()`: compile-time checked required fields, runtime-defaulted optional ones.
+///
+/// # Principles
+///
+/// Fields declared without `= $val` are *required*, fields declared with it
+/// are *optional*. Required fields are pulled out into an auxiliary
+/// `NameInit` struct with the same field names and types, emulating
+/// rsb_derive: `builder(init)` takes one `NameInit` value, so the caller must
+/// name every required field in a struct literal (`NameInit { foo: 1, baz: 2 }`)
+/// to build one, and missing one is a compile error rather than a silent
+/// positional mix-up. Optional fields are pre-filled with their default and
+/// can be overridden afterwards with `with_$field(...)`.
+///
+/// # Examples
+///
+/// ``` rust
+/// use aoko::{struct_builder, assert_eqs};
+///
+/// struct_builder!(
+///     #[derive(Debug)]
+///     pub struct A {
+///         foo: u8,
+///         pub bar: &'static str = "bar",
+///     }
+///     #[derive(Debug)]
+///     pub struct Gen<'a> {
+///         foo: &'a str,
+///         pub bar: u8 = 0,
+///     }
+///     struct B {}
+///     struct C;
+/// );
+///
+/// let test = A::builder(AInit { foo: 233 }).with_bar("baz").build();
+/// let gen_test = Gen::builder(GenInit { foo: "life" }).build();
+///
+/// assert_eqs!(
+///     233, test.foo;
+///     "baz", test.bar;
+///     "A { foo: 233, bar: \"baz\" }", format!("{:?}", test);
+///     "life", gen_test.foo;
+///     0, gen_test.bar;
+/// );
+/// ```
+#[macro_export]
+macro_rules! struct_builder {
+    ($vis:vis struct $s_name:ident;) => {$vis struct $s_name;};
+
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $(<$($generic:tt),*>)? {
+        $($field_vis:vis $field:ident: $type:ty $(= $val:expr)?),* $(,)?
+    }
+    $($tail:tt)*) => {
+        $(#[$attr])*
+        $vis struct $name $(<$($generic),*>)? {
+            $($field_vis $field: $type),*
+        }
+
+        $crate::__struct_builder_munch! {
+            @munch
+            [vis = $vis, name = $name, generics = [$(<$($generic),*>)?]]
+            [required = ]
+            [optional = ]
+            $($field: $type $(= $val)?,)*
+        }
+
+        struct_builder! {
+            $($tail)*
+        }
+    };
+
+    () => {}
+}
+
+/// Implementation detail of [`struct_builder!`]: a tt-muncher that splits the
+/// field list into required fields (no default) and optional fields (with a
+/// default), then emits the `NameBuilder` struct and its setters. The
+/// `NameInit`/`NameBuilder` type names and the `with_$field` method names are
+/// synthesized with `paste!`, since `macro_rules!` cannot build new idents on
+/// its own.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __struct_builder_munch {
+    (@munch
+        $header:tt
+        [required = $($req:tt)*]
+        [optional = $($opt:tt)*]
+        $field:ident: $type:ty, $($rest:tt)*
+    ) => {
+        $crate::__struct_builder_munch! {
+            @munch $header
+            [required = $($req)* $field: $type,]
+            [optional = $($opt)*]
+            $($rest)*
+        }
+    };
+
+    (@munch
+        $header:tt
+        [required = $($req:tt)*]
+        [optional = $($opt:tt)*]
+        $field:ident: $type:ty = $val:expr, $($rest:tt)*
+    ) => {
+        $crate::__struct_builder_munch! {
+            @munch $header
+            [required = $($req)*]
+            [optional = $($opt)* $field: $type = $val,]
+            $($rest)*
+        }
+    };
+
+    (@munch
+        [vis = $vis:vis, name = $name:ident, generics = [$($generics:tt)*]]
+        [required = $($req_field:ident: $req_type:ty,)*]
+        [optional = $($opt_field:ident: $opt_type:ty = $opt_val:expr,)*]
+    ) => {
+        ::paste::paste! {
+            $vis struct [<$name Init>] $($generics)* {
+                $($vis $req_field: $req_type,)*
+            }
+
+            $vis struct [<$name Builder>] $($generics)* {
+                $($req_field: $req_type,)*
+                $($opt_field: $opt_type,)*
+            }
+
+            impl $($generics)* $name $($generics)* {
+                $vis fn builder(init: [<$name Init>] $($generics)*) -> [<$name Builder>] $($generics)* {
+                    let [<$name Init>] { $($req_field),* } = init;
+                    [<$name Builder>] {
+                        $($req_field,)*
+                        $($opt_field: $opt_val,)*
+                    }
+                }
+            }
+
+            impl $($generics)* [<$name Builder>] $($generics)* {
+                $(
+                    $vis fn [<with_ $req_field>](mut self, v: $req_type) -> Self {
+                        self.$req_field = v;
+                        self
+                    }
+                )*
+                $(
+                    $vis fn [<with_ $opt_field>](mut self, v: $opt_type) -> Self {
+                        self.$opt_field = v;
+                        self
+                    }
+                )*
+
+                $vis fn build(self) -> $name $($generics)* {
+                    $name {
+                        $($req_field: self.$req_field,)*
+                        $($opt_field: self.$opt_field,)*
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// `Struct::default()` plus `get_$field`/`set_$field`: a [`struct_default!`]
+/// that also emits a safe accessor surface for private fields.
+///
+/// # Principles
+///
+/// Text replacement, automatic function generation. Mark a field `#[readonly]`
+/// to emit only its getter; every other field gets both a getter and a setter.
+/// Identifiers like `get_` + field name are synthesized with `paste!`, since
+/// `macro_rules!` cannot build new idents on its own.
+///
+/// # Examples
+///
+/// ``` rust
+/// use aoko::{struct_accessors, assert_eqs};
+///
+/// struct_accessors!(
+///     #[derive(Debug)]
+///     pub struct A {
+///         #[readonly]
+///         foo: u8 = 233,
+///         bar: &'static str = "abc",
+///     }
+///     struct B {}
+///     struct C;
+/// );
+///
+/// let mut test = A::default();
+/// test.set_bar("xyz");
+///
+/// assert_eqs!(
+///     &233, test.get_foo();
+///     &"xyz", test.get_bar();
+/// );
+/// ```
+#[macro_export]
+macro_rules! struct_accessors {
+    ($vis:vis struct $s_name:ident;) => {$vis struct $s_name;};
+
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $(<$($generic:tt),*>)? {
+        $($(#[$f_attr:tt])? $field_vis:vis $field:ident: $type:ty = $val:expr),* $(,)?
+    }
+    $($tail:tt)*) => {
+        $(#[$attr])*
+        $vis struct $name $(<$($generic),*>)? {
+            $($field_vis $field: $type),*
+        }
+        impl $(<$($generic),*>)? Default for $name $(<$($generic),*>)? {
+            fn default() -> Self {
+                $name {
+                    $($field: $val),*
+                }
+            }
+        }
+
+        $crate::__struct_accessors_munch! {
+            @munch
+            [vis = $vis, name = $name, generics = ($($($generic),*)?)]
+            [ro = ]
+            [rw = ]
+            $($(#[$f_attr])? $field: $type,)*
+        }
+
+        struct_accessors! {
+            $($tail)*
+        }
+    };
+
+    () => {}
+}
+
+/// Implementation detail of [`struct_accessors!`]: a tt-muncher that splits
+/// the field list into read-only fields (`#[readonly]`) and read-write
+/// fields, then emits the getters and setters.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __struct_accessors_munch {
+    (@munch $header:tt [ro = $($ro:tt)*] [rw = $($rw:tt)*]
+        #[readonly] $field:ident: $type:ty, $($rest:tt)*
+    ) => {
+        $crate::__struct_accessors_munch! {
+            @munch $header
+            [ro = $($ro)* $field: $type,]
+            [rw = $($rw)*]
+            $($rest)*
+        }
+    };
+
+    (@munch $header:tt [ro = $($ro:tt)*] [rw = $($rw:tt)*]
+        $field:ident: $type:ty, $($rest:tt)*
+    ) => {
+        $crate::__struct_accessors_munch! {
+            @munch $header
+            [ro = $($ro)*]
+            [rw = $($rw)* $field: $type,]
+            $($rest)*
+        }
+    };
+
+    (@munch
+        [vis = $vis:vis, name = $name:ident, generics = ($($generic:tt),*)]
+        [ro = $($ro_field:ident: $ro_type:ty,)*]
+        [rw = $($rw_field:ident: $rw_type:ty,)*]
+    ) => {
+        ::paste::paste! {
+            impl <$($generic),*> $name <$($generic),*> {
+                $(
+                    $vis fn [<get_ $ro_field>](&self) -> &$ro_type {
+                        &self.$ro_field
+                    }
+                )*
+                $(
+                    $vis fn [<get_ $rw_field>](&self) -> &$rw_type {
+                        &self.$rw_field
+                    }
+
+                    $vis fn [<set_ $rw_field>](&mut self, v: $rw_type) {
+                        self.$rw_field = v;
+                    }
+                )*
+            }
+        }
+    };
+}
+
+/// `Struct::new(...)` / `Struct::from_defaults(...)`: a single field block
+/// where some fields carry a `= $val` default and some don't.
+///
+/// # Principles
+///
+/// Text replacement, automatic function generation. Fields without a default
+/// become parameters of `new(...)`, which fills every other field from its
+/// default. `from_defaults(...)` takes every field explicitly, letting
+/// callers override the defaults case by case without touching `new`.
+///
+/// # Examples
+///
+/// ``` rust
+/// use aoko::{struct_new_default, assert_eqs};
+///
+/// struct_new_default!(
+///     #[derive(Debug)]
+///     pub struct A {
+///         foo: u8,
+///         pub bar: &'static str = "bar",
+///     }
+///     #[derive(Debug)]
+///     pub struct Gen<'a> {
+///         foo: &'a str,
+///         pub bar: u8 = 0,
+///     }
+///     struct B {}
+///     struct C;
+/// );
+///
+/// let test = A::new(233);
+/// let overridden = A::from_defaults(233, "baz");
+/// let gen_test = Gen::new("life");
+///
+/// assert_eqs!(
+///     233, test.foo;
+///     "bar", test.bar;
+///     "baz", overridden.bar;
+///     "life", gen_test.foo;
+///     0, gen_test.bar;
+/// );
+/// ```
+#[macro_export]
+macro_rules! struct_new_default {
+    ($vis:vis struct $s_name:ident;) => {$vis struct $s_name;};
+
+    ($(#[$attr:meta])* $vis:vis struct $name:ident $(<$($generic:tt),*>)? {
+        $($field_vis:vis $field:ident: $type:ty $(= $val:expr)?),* $(,)?
+    }
+    $($tail:tt)*) => {
+        $(#[$attr])*
+        $vis struct $name $(<$($generic),*>)? {
+            $($field_vis $field: $type),*
+        }
+
+        $crate::__struct_new_default_munch! {
+            @munch
+            [vis = $vis, name = $name, generics = [$(<$($generic),*>)?]]
+            [required = ]
+            [optional = ]
+            $($field: $type $(= $val)?,)*
+        }
+
+        struct_new_default! {
+            $($tail)*
+        }
+    };
+
+    () => {}
+}
+
+/// Implementation detail of [`struct_new_default!`]: a tt-muncher that splits
+/// the field list into non-defaulted (required) and defaulted (optional)
+/// fields, then emits `new` and `from_defaults`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __struct_new_default_munch {
+    (@munch $header:tt [required = $($req:tt)*] [optional = $($opt:tt)*]
+        $field:ident: $type:ty, $($rest:tt)*
+    ) => {
+        $crate::__struct_new_default_munch! {
+            @munch $header
+            [required = $($req)* $field: $type,]
+            [optional = $($opt)*]
+            $($rest)*
+        }
+    };
+
+    (@munch $header:tt [required = $($req:tt)*] [optional = $($opt:tt)*]
+        $field:ident: $type:ty = $val:expr, $($rest:tt)*
+    ) => {
+        $crate::__struct_new_default_munch! {
+            @munch $header
+            [required = $($req)*]
+            [optional = $($opt)* $field: $type = $val,]
+            $($rest)*
+        }
+    };
+
+    (@munch
+        [vis = $vis:vis, name = $name:ident, generics = [$($generics:tt)*]]
+        [required = $($req_field:ident: $req_type:ty,)*]
+        [optional = $($opt_field:ident: $opt_type:ty = $opt_val:expr,)*]
+    ) => {
+        impl $($generics)* $name $($generics)* {
+            $vis fn new($($req_field: $req_type),*) -> Self {
+                $name {
+                    $($req_field,)*
+                    $($opt_field: $opt_val,)*
+                }
+            }
+
+            $vis fn from_defaults($($req_field: $req_type,)* $($opt_field: $opt_type),*) -> Self {
+                $name {
+                    $($req_field,)*
+                    $($opt_field,)*
+                }
+            }
+        }
+    };
+}
+
+/// `update!(inst, { field: val, ... })`: mutate an existing instance in place.
+///
+/// # Principles
+///
+/// Text replacement: each `path: val` entry expands to `inst.path = val;`.
+/// Unlike Rust's struct-update syntax (`..`), this works on a live `&mut`
+/// value and on non-exhaustive structs, and `path` may be a dotted chain
+/// (`inner.field: val`) to reach into nested fields.
+///
+/// # Examples
+///
+/// ``` rust
+/// use aoko::{struct_default, update, assert_eqs};
+///
+/// struct_default!(
+///     #[derive(Debug)]
+///     pub struct Inner {
+///         pub baz: u8 = 0,
+///     }
+///     #[derive(Debug)]
+///     pub struct A {
+///         pub foo: u8 = 233,
+///         pub bar: &'static str = "abc",
+///         pub inner: Inner = Inner::default(),
+///     }
+/// );
+///
+/// let mut test = A::default();
+/// update!(test, {
+///     foo: 1,
+///     bar: "x",
+///     inner.baz: 9,
+/// });
+///
+/// assert_eqs!(
+///     1, test.foo;
+///     "x", test.bar;
+///     9, test.inner.baz;
+/// );
+/// ```
+#[macro_export]
+macro_rules! update {
+    ($inst:expr, { $($path:ident $(.$more:ident)* : $val:expr),* $(,)? }) => {
+        $($inst.$path $(.$more)* = $val;)*
+    };
+}
+
+#[cfg(test)]
+mod struct_accessors_tests {
+    struct_accessors!(
+        #[derive(Debug, PartialEq)]
+        struct Sample {
+            #[readonly]
+            foo: u8 = 233,
+            bar: &'static str = "abc",
+        }
+    );
+
+    #[test]
+    fn get_and_set() {
+        let mut s = Sample::default();
+        assert_eq!(&233, s.get_foo());
+        assert_eq!(&"abc", s.get_bar());
+
+        s.set_bar("xyz");
+        assert_eq!(&"xyz", s.get_bar());
+    }
+}